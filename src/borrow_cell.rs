@@ -0,0 +1,83 @@
+use core::cell::Cell;
+
+/// A trait for interior mutability with value semantics.
+///
+/// Unlike [`BorrowRef`](crate::BorrowRef)/[`BorrowRefMut`](crate::BorrowRefMut),
+/// which hand out a guard, a [`Cell`](core::cell::Cell) lets callers `get`,
+/// `set`, `replace` and `take` the wrapped value directly. This trait abstracts
+/// over "any cell-like container with value semantics", the same way the other
+/// traits abstract over `RefCell`-like containers.
+/// ```
+/// use std::cell::Cell;
+/// use borrow_trait::{ BorrowCell };
+///
+/// fn takes_bound<T>(value: &T) -> i32
+/// where
+///     T: BorrowCell<Target = i32>,
+/// {
+///     value.set(value.get() + 1);
+///     value.get()
+/// }
+///
+/// let value = Cell::new(41);
+/// assert_eq!(takes_bound(&value), 42);
+/// ```
+pub trait BorrowCell {
+    /// The type, that is wrapped by the implementation.
+    /// # Example
+    /// A `Cell<T>` wraps around `T`, therefore `Target` has to be `T`
+    /// ``` ignore
+    /// type Target = T;
+    /// ```
+    type Target;
+
+    /// Returns a copy of the contained value.
+    fn get(&self) -> Self::Target
+    where
+        Self::Target: Copy;
+
+    /// Sets the contained value.
+    fn set(&self, value: Self::Target);
+
+    /// Replaces the contained value with `value`, and returns the old value.
+    fn replace(&self, value: Self::Target) -> Self::Target;
+
+    /// Takes the contained value, leaving `Default::default()` in its place.
+    fn take(&self) -> Self::Target
+    where
+        Self::Target: Default;
+}
+
+macro_rules! borrow_cell {
+    ( $( $name:ty ),* $(,)* ) => {
+        $(
+            impl<T> BorrowCell for $name {
+                type Target = T;
+
+                #[inline]
+                fn get(&self) -> T
+                where
+                    T: Copy,
+                {
+                    Cell::get(self)
+                }
+
+                #[inline]
+                fn set(&self, value: T) { Cell::set(self, value) }
+
+                #[inline]
+                fn replace(&self, value: T) -> T { Cell::replace(self, value) }
+
+                #[inline]
+                fn take(&self) -> T
+                where
+                    T: Default,
+                {
+                    Cell::take(self)
+                }
+            }
+        )*
+    };
+}
+
+borrow_cell![Cell<T>, &Cell<T>, &mut Cell<T>];
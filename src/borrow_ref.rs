@@ -86,7 +86,7 @@ pub trait BorrowRef<'a> {
 
 macro_rules! borrow_ref {
     (
-        $( $pointer:ty => $body:path => $( $name:ty ),* );*
+        $( $pointer:ty => $body:expr => $( $name:ty ),* );*
         $(;)* // <- allows to have a trailing semi-colon
     ) => {
         $(
@@ -126,3 +126,30 @@ borrow_ref![
     cell::RefCell::borrow =>
     cell::RefCell<T>, &cell::RefCell<T>, &mut cell::RefCell<T>;
 ];
+
+// RwLock
+//
+// Unlike `RefCell`, the std locks return a `Result` from `read`, which is
+// `Err` only if the lock is poisoned (a prior holder panicked while holding
+// it), not merely contended; contended reads block instead of erroring. We
+// unwrap here since `BorrowRef` has no room for that error in its contract.
+#[cfg(all(feature = "lock", feature = "std"))]
+use std::sync::{RwLock, RwLockReadGuard};
+#[cfg(all(feature = "lock", feature = "std"))]
+borrow_ref![
+    RwLockReadGuard<'a, T> => |s: &'a RwLock<T>| RwLock::read(s).unwrap() =>
+    RwLock<T>, &RwLock<T>, &mut RwLock<T>;
+];
+
+// parking_lot
+//
+// Unlike the std lock, `parking_lot`'s `read` returns the guard directly
+// without a `Result`, so the plain `borrow_ref!` macro is a clean fit.
+#[cfg(feature = "parking_lot")]
+use parking_lot::{RwLock as PlRwLock, RwLockReadGuard as PlRwLockReadGuard};
+#[cfg(feature = "parking_lot")]
+borrow_ref![
+    PlRwLockReadGuard<'a, T> =>
+    PlRwLock::read =>
+    PlRwLock<T>, &PlRwLock<T>, &mut PlRwLock<T>;
+];
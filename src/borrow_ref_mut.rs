@@ -97,7 +97,7 @@ pub trait BorrowRefMut<'a> {
 
 macro_rules! borrow_ref_mut {
     (
-        $( $pointer:ty => $body:path => $( $name:ty ),* );*
+        $( $pointer:ty => $body:expr => $( $name:ty ),* );*
         $(;)* // <- allows to have a trailing semi-colon
     ) => {
         $(
@@ -136,3 +136,35 @@ borrow_ref_mut![
     cell::RefMut<'a, T> =>
     cell::RefCell::borrow_mut => cell::RefCell<T>, &cell::RefCell<T>, &mut cell::RefCell<T>;
 ];
+
+// RwLock / Mutex
+//
+// The std locks return a `Result` from `write`/`lock`, which is `Err` only if
+// the lock is poisoned (a prior holder panicked while holding it), not merely
+// contended; contended calls block instead of erroring. We unwrap here since
+// `BorrowRefMut` has no room for that error in its contract.
+#[cfg(all(feature = "lock", feature = "std"))]
+use std::sync::{Mutex, MutexGuard, RwLock, RwLockWriteGuard};
+#[cfg(all(feature = "lock", feature = "std"))]
+borrow_ref_mut![
+    RwLockWriteGuard<'a, T> => |s: &'a RwLock<T>| RwLock::write(s).unwrap() =>
+    RwLock<T>, &RwLock<T>, &mut RwLock<T>;
+    MutexGuard<'a, T> => |s: &'a Mutex<T>| Mutex::lock(s).unwrap() =>
+    Mutex<T>, &Mutex<T>, &mut Mutex<T>;
+];
+
+// parking_lot
+//
+// Unlike the std locks, `parking_lot`'s `write`/`lock` return the guard
+// directly without a `Result`, so the plain `borrow_ref_mut!` macro is a clean
+// fit.
+#[cfg(feature = "parking_lot")]
+use parking_lot::{
+    Mutex as PlMutex, MutexGuard as PlMutexGuard, RwLock as PlRwLock,
+    RwLockWriteGuard as PlRwLockWriteGuard,
+};
+#[cfg(feature = "parking_lot")]
+borrow_ref_mut![
+    PlRwLockWriteGuard<'a, T> => PlRwLock::write => PlRwLock<T>, &PlRwLock<T>, &mut PlRwLock<T>;
+    PlMutexGuard<'a, T> => PlMutex::lock => PlMutex<T>, &PlMutex<T>, &mut PlMutex<T>;
+];
@@ -68,6 +68,10 @@
 //! # Features
 //! + `atomic_refcell`, implements traits for [AtomicRefCell] (thread-safe [RefCell])
 //! + `cell`, implements traits for [cell::RefCell] (this is not [std::cell::RefCell])
+//! + `lock`, implements traits for [RwLock](std::sync::RwLock) (the `Sync` analogue of
+//! [RefCell]) and [Mutex](std::sync::Mutex)
+//! + `parking_lot`, implements traits for `parking_lot`'s `RwLock` and `Mutex`, whose guards
+//! are smaller and whose uncontended locking avoids syscalls
 //!
 //! `no_std` support can be enabled by adding the following to the `Cargo.toml`:
 //! ```toml
@@ -109,11 +113,21 @@
 extern crate alloc;
 mod borrow_ref;
 mod borrow_ref_mut;
+mod try_borrow_ref;
+mod try_borrow_ref_mut;
+mod map_borrow;
+mod map_borrow_mut;
+mod borrow_cell;
 #[cfg(feature = "alloc")]
 mod pointers;
 
 pub use borrow_ref::*;
 pub use borrow_ref_mut::*;
+pub use try_borrow_ref::*;
+pub use try_borrow_ref_mut::*;
+pub use map_borrow::*;
+pub use map_borrow_mut::*;
+pub use borrow_cell::*;
 #[cfg(feature = "alloc")]
 pub use pointers::*;
 
@@ -0,0 +1,89 @@
+use core::cell::Ref;
+use core::ops::Deref;
+
+/// A trait for projecting an immutable borrow onto one of its components.
+///
+/// This mirrors [`Ref::map`](core::cell::Ref::map): it consumes a guard and
+/// returns a new guard that derefs to a sub-component of the borrowed value,
+/// without releasing the borrow. Because the projected type `U` is part of the
+/// trait, generic code over `T: for<'a> BorrowRef<'a>` can narrow a borrow to a
+/// field across the `RefCell`, `AtomicRefCell` and `cell::RefCell` guards. The
+/// guards of the `lock` and `parking_lot` features do not implement this trait.
+/// ```
+/// use std::ops::Deref;
+/// use std::cell::RefCell;
+/// use borrow_trait::{ BorrowRef, MapBorrow };
+///
+/// struct Foo { bar: String }
+///
+/// fn borrow_bar<T>(value: &T) -> impl Deref<Target = String> + '_
+/// where
+///     T: for<'a> BorrowRef<'a, Target = Foo>,
+///     for<'a> <T as BorrowRef<'a>>::Pointer: MapBorrow<'a, String, Target = Foo>,
+/// {
+///     MapBorrow::map(value.borrow(), |foo| &foo.bar)
+/// }
+///
+/// let value = RefCell::new(Foo { bar: "Hello World".to_string() });
+/// assert_eq!(borrow_bar(&value).deref(), &"Hello World".to_string());
+/// ```
+pub trait MapBorrow<'a, U: 'a>: Deref {
+    /// The guard returned by [`map`](MapBorrow::map), derefing to `U`.
+    /// # Example
+    /// A `Ref<'a, T>` maps to `Ref<'a, U>`.
+    /// ``` ignore
+    /// type Mapped = Ref<'a, U>;
+    /// ```
+    type Mapped: 'a + Deref<Target = U>;
+
+    /// Makes a new guard for a component of the borrowed data.
+    ///
+    /// The borrow stays active for as long as the returned guard lives.
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&Self::Target) -> &U;
+}
+
+impl<'a, T, U: 'a> MapBorrow<'a, U> for Ref<'a, T> {
+    type Mapped = Ref<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&Self::Target) -> &U,
+    {
+        Ref::map(self, f)
+    }
+}
+
+// AtomicRefCell
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+use atomic_refcell::AtomicRef;
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+impl<'a, T, U: 'a> MapBorrow<'a, U> for AtomicRef<'a, T> {
+    type Mapped = AtomicRef<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&Self::Target) -> &U,
+    {
+        AtomicRef::map(self, f)
+    }
+}
+
+// Cell
+#[cfg(all(feature = "cell", feature = "alloc"))]
+use cell;
+#[cfg(all(feature = "cell", feature = "alloc"))]
+impl<'a, T, U: 'a> MapBorrow<'a, U> for cell::Ref<'a, T> {
+    type Mapped = cell::Ref<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&Self::Target) -> &U,
+    {
+        cell::Ref::map(self, f)
+    }
+}
@@ -0,0 +1,90 @@
+use core::cell::RefMut;
+use core::ops::DerefMut;
+
+/// A trait for projecting a mutable borrow onto one of its components.
+///
+/// This mirrors [`RefMut::map`](core::cell::RefMut::map): it consumes a guard
+/// and returns a new guard that derefs to a sub-component of the borrowed
+/// value, without releasing the borrow. Because the projected type `U` is part
+/// of the trait, generic code over `T: for<'a> BorrowRefMut<'a>` can narrow a
+/// borrow to a field across the `RefCell`, `AtomicRefCell` and `cell::RefCell`
+/// guards. The guards of the `lock` and `parking_lot` features do not implement
+/// this trait.
+/// ```
+/// use std::ops::DerefMut;
+/// use std::cell::RefCell;
+/// use borrow_trait::{ BorrowRefMut, MapBorrowMut };
+///
+/// struct Foo { bar: String }
+///
+/// fn borrow_bar<T>(value: &T) -> impl DerefMut<Target = String> + '_
+/// where
+///     T: for<'a> BorrowRefMut<'a, Target = Foo>,
+///     for<'a> <T as BorrowRefMut<'a>>::Pointer: MapBorrowMut<'a, String, Target = Foo>,
+/// {
+///     MapBorrowMut::map(value.borrow_mut(), |foo| &mut foo.bar)
+/// }
+///
+/// let value = RefCell::new(Foo { bar: "Hello World".to_string() });
+/// assert_eq!(borrow_bar(&value).deref_mut(), &mut "Hello World".to_string());
+/// ```
+pub trait MapBorrowMut<'a, U: 'a>: DerefMut {
+    /// The guard returned by [`map`](MapBorrowMut::map), derefing to `U`.
+    /// # Example
+    /// A `RefMut<'a, T>` maps to `RefMut<'a, U>`.
+    /// ``` ignore
+    /// type Mapped = RefMut<'a, U>;
+    /// ```
+    type Mapped: 'a + DerefMut<Target = U>;
+
+    /// Makes a new guard for a component of the mutably borrowed data.
+    ///
+    /// The borrow stays active for as long as the returned guard lives.
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&mut Self::Target) -> &mut U;
+}
+
+impl<'a, T, U: 'a> MapBorrowMut<'a, U> for RefMut<'a, T> {
+    type Mapped = RefMut<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&mut Self::Target) -> &mut U,
+    {
+        RefMut::map(self, f)
+    }
+}
+
+// AtomicRefCell
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+use atomic_refcell::AtomicRefMut;
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+impl<'a, T, U: 'a> MapBorrowMut<'a, U> for AtomicRefMut<'a, T> {
+    type Mapped = AtomicRefMut<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&mut Self::Target) -> &mut U,
+    {
+        AtomicRefMut::map(self, f)
+    }
+}
+
+// Cell
+#[cfg(all(feature = "cell", feature = "alloc"))]
+use cell;
+#[cfg(all(feature = "cell", feature = "alloc"))]
+impl<'a, T, U: 'a> MapBorrowMut<'a, U> for cell::RefMut<'a, T> {
+    type Mapped = cell::RefMut<'a, U>;
+
+    #[inline]
+    fn map<F>(self, f: F) -> Self::Mapped
+    where
+        F: FnOnce(&mut Self::Target) -> &mut U,
+    {
+        cell::RefMut::map(self, f)
+    }
+}
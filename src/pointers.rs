@@ -1,4 +1,4 @@
-use crate::{BorrowRef, BorrowRefMut};
+use crate::{BorrowCell, BorrowRef, BorrowRefMut, TryBorrowRef, TryBorrowRefMut};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
 
@@ -25,6 +25,47 @@ macro_rules! pointer_trait {
 
                 fn borrow(&'a self) -> Self::Pointer { self.as_ref().borrow() }
             }
+
+            impl<'a, K: 'a, T: 'a> TryBorrowRefMut<'a> for $name
+            where
+                T: TryBorrowRefMut<'a, Target = K>,
+            {
+                type Target = K;
+                type Pointer = <T as TryBorrowRefMut<'a>>::Pointer;
+                type Error = <T as TryBorrowRefMut<'a>>::Error;
+
+                fn try_borrow_mut(&'a self) -> Result<Self::Pointer, Self::Error> {
+                    self.as_ref().try_borrow_mut()
+                }
+            }
+
+            impl<'a, K: 'a, T: 'a> TryBorrowRef<'a> for $name
+            where
+                T: TryBorrowRef<'a, Target = K>,
+            {
+                type Target = K;
+                type Pointer = <T as TryBorrowRef<'a>>::Pointer;
+                type Error = <T as TryBorrowRef<'a>>::Error;
+
+                fn try_borrow(&'a self) -> Result<Self::Pointer, Self::Error> {
+                    self.as_ref().try_borrow()
+                }
+            }
+
+            impl<K, T> BorrowCell for $name
+            where
+                T: BorrowCell<Target = K>,
+            {
+                type Target = K;
+
+                fn get(&self) -> K where K: Copy { self.as_ref().get() }
+
+                fn set(&self, value: K) { self.as_ref().set(value) }
+
+                fn replace(&self, value: K) -> K { self.as_ref().replace(value) }
+
+                fn take(&self) -> K where K: Default { self.as_ref().take() }
+            }
         )*
     };
 }
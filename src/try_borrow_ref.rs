@@ -0,0 +1,131 @@
+use core::cell::{BorrowError, Ref, RefCell};
+use core::ops::Deref;
+
+/// A trait for fallibly borrowing data.
+///
+/// The `try_borrow` function returns an immutable reference to `Self::Target`,
+/// or an error if the value is currently mutably borrowed. It is the
+/// non-panicking sibling of [`BorrowRef`](crate::BorrowRef), letting generic
+/// code decide how to handle a conflicting borrow.
+/// ```
+/// use std::ops::Deref;
+/// use std::cell::RefCell;
+/// use borrow_trait::{ TryBorrowRef };
+///
+/// fn takes_bound<T>(value: &T)
+/// where
+///     T: for<'a> TryBorrowRef<'a, Target = String>,
+/// {
+///     match value.try_borrow() {
+///         Ok(borrowed) => assert_eq!(borrowed.deref(), &"Hello World".to_string()),
+///         Err(_) => unreachable!(),
+///     }
+/// }
+///
+/// let value = RefCell::new("Hello World".to_string());
+/// takes_bound(&value)
+/// ```
+/// # Implementation Example
+/// Implementing `TryBorrowRef` for RefCell:
+/// ``` ignore
+/// use std::cell::{ BorrowError, Ref, RefCell };
+/// use borrow_trait::{ TryBorrowRef };
+///
+/// impl<'a, T: 'a> TryBorrowRef<'a> for RefCell<T> {
+///     type Target = T;
+///     type Pointer = Ref<'a, Self::Target>;
+///     type Error = BorrowError;
+///
+///     fn try_borrow(&'a self) -> Result<Self::Pointer, Self::Error> { RefCell::try_borrow(self) }
+/// }
+/// ```
+pub trait TryBorrowRef<'a> {
+    /// The type, that is wrapped by the implementation.
+    /// # Example
+    /// A `RefCell<T>` wraps around `T`, therefore `Target` has to be `T`
+    /// ``` ignore
+    /// type Target = T;
+    /// ```
+    type Target;
+    /// The type returned by the implementor.
+    /// # Example
+    /// A `RefCell` returns `Ref` so `Pointer` has to be `Ref`.
+    /// ``` ignore
+    /// type Pointer = Ref<'a, Self::Target>;
+    /// ```
+    type Pointer: 'a + Deref<Target = Self::Target>;
+    /// The error returned when the value cannot be borrowed.
+    /// # Example
+    /// A `RefCell` returns a [`BorrowError`](core::cell::BorrowError).
+    /// ``` ignore
+    /// type Error = BorrowError;
+    /// ```
+    type Error;
+
+    /// Immutably borrows the wrapped value, returning an error if the value is
+    /// currently mutably borrowed.
+    ///
+    /// This is the non-panicking variant of
+    /// [`borrow`](crate::BorrowRef::borrow).
+    /// # Example
+    /// ```
+    /// use std::cell::RefCell;
+    /// use borrow_trait::{ TryBorrowRef };
+    ///
+    /// fn takes_bound<T>(value: &T)
+    /// where
+    ///     T: for<'a> TryBorrowRef<'a, Target = String>,
+    /// {
+    ///     let first_borrow = value.try_borrow();
+    ///     let second_borrow = value.try_borrow();
+    ///
+    ///     assert!(first_borrow.is_ok());
+    ///     assert!(second_borrow.is_ok());
+    /// }
+    ///
+    /// let value = RefCell::new("Hello World".to_string());
+    /// takes_bound(&value)
+    /// ```
+    fn try_borrow(&'a self) -> Result<Self::Pointer, Self::Error>;
+}
+
+macro_rules! try_borrow_ref {
+    (
+        $( $pointer:ty, $error:ty => $body:path => $( $name:ty ),* );*
+        $(;)* // <- allows to have a trailing semi-colon
+    ) => {
+        $(
+            $(
+                impl<'a, T: 'a> TryBorrowRef<'a> for $name {
+                    type Target = T;
+                    type Pointer = $pointer;
+                    type Error = $error;
+
+                    #[inline]
+                    fn try_borrow(&'a self) -> Result<Self::Pointer, Self::Error> { $body(self) }
+                }
+            )* // repeat for each value, seperated by ','
+        )* // repeat for each line, seperated by ';'
+    };
+}
+
+try_borrow_ref![
+    Ref<'a, T>, BorrowError => RefCell::try_borrow => RefCell<T>, &RefCell<T>, &mut RefCell<T>;
+];
+
+// AtomicRefCell
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+use atomic_refcell::{AtomicRef, AtomicRefCell, BorrowError as AtomicBorrowError};
+#[cfg(all(feature = "atomic_refcell", feature = "alloc"))]
+try_borrow_ref![
+    AtomicRef<'a, T>, AtomicBorrowError =>
+    AtomicRefCell::try_borrow =>
+    AtomicRefCell<T>, &AtomicRefCell<T>, &mut AtomicRefCell<T>;
+];
+
+// Cell
+//
+// Unlike `core::cell` and `atomic_refcell`, the `cell` crate does not expose
+// a reachable `BorrowError` type (it lives in a private module and is not
+// re-exported), so there is currently no error type to implement
+// `TryBorrowRef` with. Revisit once upstream exposes one.
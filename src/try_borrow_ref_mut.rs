@@ -0,0 +1,134 @@
+use core::cell::{BorrowMutError, RefCell, RefMut};
+use core::ops::DerefMut;
+
+/// A trait for fallibly borrowing data mutably.
+///
+/// The `try_borrow_mut` function returns a mutable reference to `Self::Target`,
+/// or an error if the value is currently borrowed. It is the non-panicking
+/// sibling of [`BorrowRefMut`](crate::BorrowRefMut), letting generic code
+/// decide how to handle a conflicting borrow.
+/// ```
+/// use std::ops::DerefMut;
+/// use std::cell::RefCell;
+/// use borrow_trait::{ TryBorrowRefMut };
+///
+/// fn takes_bound<T>(value: &T)
+/// where
+///     T: for<'a> TryBorrowRefMut<'a, Target = String>,
+/// {
+///     match value.try_borrow_mut() {
+///         Ok(mut borrowed) => assert_eq!(borrowed.deref_mut(), &mut "Hello World".to_string()),
+///         Err(_) => unreachable!(),
+///     }
+/// }
+///
+/// let value = RefCell::new("Hello World".to_string());
+/// takes_bound(&value)
+/// ```
+/// # Implementation Example
+/// Implementing `TryBorrowRefMut` for RefCell:
+/// ``` ignore
+/// use std::cell::{ BorrowMutError, RefMut, RefCell };
+/// use borrow_trait::{ TryBorrowRefMut };
+///
+/// impl<'a, T: 'a> TryBorrowRefMut<'a> for RefCell<T> {
+///     type Target = T;
+///     type Pointer = RefMut<'a, Self::Target>;
+///     type Error = BorrowMutError;
+///
+///     fn try_borrow_mut(&'a self) -> Result<Self::Pointer, Self::Error> {
+///         RefCell::try_borrow_mut(self)
+///     }
+/// }
+/// ```
+pub trait TryBorrowRefMut<'a> {
+    /// The type, that is wrapped by the implementation.
+    /// # Example
+    /// A `RefCell<T>` wraps around `T` therefore `Target` has to be `T`
+    /// ``` ignore
+    /// type Target = T;
+    /// ```
+    type Target;
+    /// The type returned by the implementor.
+    /// # Example
+    /// A `RefCell` returns `RefMut` so `Pointer` has to be `RefMut`.
+    /// ``` ignore
+    /// type Pointer = RefMut<'a, Self::Target>;
+    /// ```
+    type Pointer: 'a + DerefMut<Target = Self::Target>;
+    /// The error returned when the value cannot be borrowed.
+    /// # Example
+    /// A `RefCell` returns a [`BorrowMutError`](core::cell::BorrowMutError).
+    /// ``` ignore
+    /// type Error = BorrowMutError;
+    /// ```
+    type Error;
+
+    /// Mutably borrows the wrapped value, returning an error if the value is
+    /// currently borrowed.
+    ///
+    /// This is the non-panicking variant of
+    /// [`borrow_mut`](crate::BorrowRefMut::borrow_mut).
+    /// # Example
+    /// ```
+    /// use std::cell::RefCell;
+    /// use borrow_trait::{ TryBorrowRefMut };
+    ///
+    /// fn takes_bound<T>(value: &T)
+    /// where
+    ///     T: for<'a> TryBorrowRefMut<'a, Target = String>,
+    /// {
+    ///     let first_borrow = value.try_borrow_mut();
+    ///     let second_borrow = value.try_borrow_mut(); // this fails, while the first is active
+    ///
+    ///     assert!(first_borrow.is_ok());
+    ///     assert!(second_borrow.is_err());
+    /// }
+    ///
+    /// let value = RefCell::new("Hello World".to_string());
+    /// takes_bound(&value)
+    /// ```
+    fn try_borrow_mut(&'a self) -> Result<Self::Pointer, Self::Error>;
+}
+
+macro_rules! try_borrow_ref_mut {
+    (
+        $( $pointer:ty, $error:ty => $body:path => $( $name:ty ),* );*
+        $(;)* // <- allows to have a trailing semi-colon
+    ) => {
+        $(
+            $(
+                impl<'a, T: 'a> TryBorrowRefMut<'a> for $name {
+                    type Target = T;
+                    type Pointer = $pointer;
+                    type Error = $error;
+
+                    #[inline]
+                    fn try_borrow_mut(&'a self) -> Result<Self::Pointer, Self::Error> { $body(self) }
+                }
+            )* // repeat for each value, seperated by ','
+        )* // repeat for each line, seperated by ';'
+    }
+}
+
+try_borrow_ref_mut![
+    RefMut<'a, T>, BorrowMutError =>
+    RefCell::try_borrow_mut => RefCell<T>, &RefCell<T>, &mut RefCell<T>;
+];
+
+// AtomicRefCell
+#[cfg(feature = "atomic_refcell")]
+use atomic_refcell::{AtomicRefCell, AtomicRefMut, BorrowMutError as AtomicBorrowMutError};
+#[cfg(feature = "atomic_refcell")]
+try_borrow_ref_mut![
+    AtomicRefMut<'a, T>, AtomicBorrowMutError =>
+    AtomicRefCell::try_borrow_mut =>
+    AtomicRefCell<T>, &AtomicRefCell<T>, &mut AtomicRefCell<T>;
+];
+
+// Cell
+//
+// Unlike `core::cell` and `atomic_refcell`, the `cell` crate does not expose
+// a reachable `BorrowMutError` type (it lives in a private module and is not
+// re-exported), so there is currently no error type to implement
+// `TryBorrowRefMut` with. Revisit once upstream exposes one.
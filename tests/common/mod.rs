@@ -0,0 +1,23 @@
+use std::io::{Cursor, Read};
+
+use borrow_trait::{BorrowRef, BorrowRefMut};
+
+pub fn takes_ref_bound<'a, T>(value: &'a T) -> Vec<u8>
+where
+    T: BorrowRef<'a, Target = Cursor<Vec<u8>>>,
+{
+    value.borrow().get_ref().clone()
+}
+
+pub fn takes_mut_bound<'a, C, T>(value: &'a T) -> Vec<u8>
+where
+    T: BorrowRefMut<'a, Target = C>,
+    C: Read,
+{
+    let mut result = vec![];
+    value
+        .borrow_mut()
+        .read_to_end(&mut result)
+        .expect("Failed to read from `value: T`");
+    result
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "lock")]
+
+use std::io::Cursor;
+use std::sync::{Mutex, RwLock};
+
+mod common;
+use common::{takes_mut_bound, takes_ref_bound};
+
+#[test]
+fn rwlock() {
+    let value = RwLock::new(Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    assert_eq!(takes_mut_bound(&value), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn rwlock_read() {
+    let value = RwLock::new(Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    assert_eq!(takes_ref_bound(&value), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn mutex() {
+    let value = Mutex::new(Cursor::new(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    assert_eq!(takes_mut_bound(&value), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+}
@@ -1,8 +1,8 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::io::{Cursor, Read};
 use std::rc::Rc;
 
-use borrow_trait::BorrowRefMut;
+use borrow_trait::{BorrowCell, BorrowRef, BorrowRefMut, MapBorrow, TryBorrowRef};
 
 fn takes_bound<'a, C, T>(value: &'a T) -> Vec<u8>
 where
@@ -30,3 +30,54 @@ fn rc_refcell() {
     ])));
     assert_eq!(takes_bound(&value), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
 }
+
+fn takes_try_borrow_bound<'a, C, T>(value: &'a T) -> Result<C, ()>
+where
+    T: TryBorrowRef<'a, Target = C>,
+    C: Clone,
+{
+    value.try_borrow().map(|borrowed| (*borrowed).clone()).map_err(|_| ())
+}
+
+#[test]
+fn try_borrow_refcell() {
+    let value = RefCell::new("Hello World".to_string());
+    assert_eq!(takes_try_borrow_bound(&value), Ok("Hello World".to_string()));
+
+    let _held = value.borrow_mut();
+    assert_eq!(takes_try_borrow_bound(&value), Err(()));
+}
+
+struct Foo {
+    bar: String,
+}
+
+fn borrow_bar<T>(value: &T) -> impl std::ops::Deref<Target = String> + '_
+where
+    T: for<'a> BorrowRef<'a, Target = Foo>,
+    for<'a> <T as BorrowRef<'a>>::Pointer: MapBorrow<'a, String, Target = Foo>,
+{
+    MapBorrow::map(value.borrow(), |foo| &foo.bar)
+}
+
+#[test]
+fn map_borrow_refcell() {
+    let value = RefCell::new(Foo {
+        bar: "Hello World".to_string(),
+    });
+    assert_eq!(*borrow_bar(&value), "Hello World".to_string());
+}
+
+fn takes_cell_bound<T>(value: &T) -> i32
+where
+    T: BorrowCell<Target = i32>,
+{
+    value.set(value.get() + 1);
+    value.get()
+}
+
+#[test]
+fn borrow_cell() {
+    let value = Cell::new(41);
+    assert_eq!(takes_cell_bound(&value), 42);
+}